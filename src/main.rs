@@ -9,50 +9,345 @@ pub trait Validator<T, E> {
     fn validate(&self, t: &T) -> Vec<E>;
 }
 
-pub struct DriverValidator {
-    rules: Vec<Box<dyn Rule<Driver, DriverError>>>,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
 }
 
-impl DriverValidator {
-    pub fn new(rules: Vec<Box<dyn Rule<Driver, DriverError>>>) -> Self {
-        Self { rules }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    CollectAll,
+    ShortCircuit,
+}
+
+pub struct ValidationReport<E> {
+    pub errors: Vec<E>,
+    pub warnings: Vec<E>,
+}
+
+impl<E> ValidationReport<E> {
+    pub fn is_accepted(&self) -> bool {
+        self.errors.is_empty()
     }
 }
 
-impl Validator<Driver, DriverError> for DriverValidator {
-    fn validate(&self, driver: &Driver) -> Vec<DriverError> {
-        let (_, errors): (Vec<_>, Vec<_>) = self
-            .rules
-            .iter()
-            .map(|rule| rule.run(driver))
-            .partition(Result::is_ok);
-        errors.into_iter().map(Result::unwrap_err).collect()
+pub struct RuleSet<T, E> {
+    rules: Vec<(Box<dyn Rule<T, E>>, Severity)>,
+    mode: Mode,
+}
+
+impl<T, E> RuleSet<T, E> {
+    pub fn new(rules: Vec<Box<dyn Rule<T, E>>>) -> Self {
+        Self {
+            rules: rules
+                .into_iter()
+                .map(|rule| (rule, Severity::Error))
+                .collect(),
+            mode: Mode::CollectAll,
+        }
+    }
+
+    pub fn validate_report(&self, t: &T) -> ValidationReport<E> {
+        let mut report = ValidationReport {
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        };
+
+        for (rule, severity) in &self.rules {
+            if let Err(e) = rule.run(t) {
+                match severity {
+                    Severity::Error => {
+                        report.errors.push(e);
+                        if self.mode == Mode::ShortCircuit {
+                            break;
+                        }
+                    }
+                    Severity::Warning => report.warnings.push(e),
+                }
+            }
+        }
+
+        report
     }
 }
 
-pub struct DriverValidatorBuilder {
-    rules: Vec<Box<dyn Rule<Driver, DriverError>>>,
+impl<T, E> Validator<T, E> for RuleSet<T, E> {
+    fn validate(&self, t: &T) -> Vec<E> {
+        self.validate_report(t).errors
+    }
+}
+
+pub struct RuleSetBuilder<T, E> {
+    rules: Vec<(Box<dyn Rule<T, E>>, Severity)>,
+    mode: Mode,
 }
 
-impl DriverValidatorBuilder {
+impl<T, E> RuleSetBuilder<T, E> {
     pub fn new() -> Self {
-        Self { rules: Vec::new() }
+        Self {
+            rules: Vec::new(),
+            mode: Mode::CollectAll,
+        }
+    }
+
+    pub fn with_rule(mut self, rule: Box<dyn Rule<T, E>>) -> Self {
+        self.rules.push((rule, Severity::Error));
+        self
+    }
+
+    pub fn with_rule_severity(mut self, rule: Box<dyn Rule<T, E>>, severity: Severity) -> Self {
+        self.rules.push((rule, severity));
+        self
     }
 
-    pub fn with_rule(mut self, rule: Box<dyn Rule<Driver, DriverError>>) -> Self {
-        self.rules.push(rule);
+    pub fn with_mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
         self
     }
 
-    pub fn build(self) -> DriverValidator {
-        DriverValidator { rules: self.rules }
+    pub fn build(self) -> RuleSet<T, E> {
+        RuleSet {
+            rules: self.rules,
+            mode: self.mode,
+        }
+    }
+}
+
+impl<T, E> Default for RuleSetBuilder<T, E> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
+pub type DriverValidator = RuleSet<Driver, DriverError>;
+pub type DriverValidatorBuilder = RuleSetBuilder<Driver, DriverError>;
+
 pub trait Rule<T, E> {
     fn run(&self, t: &T) -> Result<(), E>;
 }
 
+pub trait RuleExt<T, E>: Rule<T, E> {
+    fn and(self, other: Box<dyn Rule<T, E>>) -> Box<dyn Rule<T, E>>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        Box::new(And {
+            left: Box::new(self),
+            right: other,
+        })
+    }
+
+    fn or(self, other: Box<dyn Rule<T, E>>) -> Box<dyn Rule<T, E>>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        Box::new(Or {
+            left: Box::new(self),
+            right: other,
+        })
+    }
+
+    fn not(self, error: E) -> Box<dyn Rule<T, E>>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        E: Clone + 'static,
+    {
+        Box::new(Not {
+            inner: Box::new(self),
+            error,
+        })
+    }
+}
+
+impl<T, E, R: Rule<T, E>> RuleExt<T, E> for R {}
+
+pub fn all<T, E>(rules: Vec<Box<dyn Rule<T, E>>>) -> Box<dyn Rule<T, E>>
+where
+    T: 'static,
+    E: 'static,
+{
+    Box::new(All { rules })
+}
+
+pub fn any<T, E>(rules: Vec<Box<dyn Rule<T, E>>>) -> Box<dyn Rule<T, E>>
+where
+    T: 'static,
+    E: 'static,
+{
+    Box::new(Any { rules })
+}
+
+struct And<T, E> {
+    left: Box<dyn Rule<T, E>>,
+    right: Box<dyn Rule<T, E>>,
+}
+
+impl<T, E> Rule<T, E> for And<T, E> {
+    fn run(&self, t: &T) -> Result<(), E> {
+        self.left.run(t)?;
+        self.right.run(t)
+    }
+}
+
+struct Or<T, E> {
+    left: Box<dyn Rule<T, E>>,
+    right: Box<dyn Rule<T, E>>,
+}
+
+impl<T, E> Rule<T, E> for Or<T, E> {
+    fn run(&self, t: &T) -> Result<(), E> {
+        match self.left.run(t) {
+            Ok(()) => Ok(()),
+            Err(_) => self.right.run(t),
+        }
+    }
+}
+
+struct Not<T, E> {
+    inner: Box<dyn Rule<T, E>>,
+    error: E,
+}
+
+impl<T, E: Clone> Rule<T, E> for Not<T, E> {
+    fn run(&self, t: &T) -> Result<(), E> {
+        match self.inner.run(t) {
+            Ok(()) => Err(self.error.clone()),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+struct All<T, E> {
+    rules: Vec<Box<dyn Rule<T, E>>>,
+}
+
+impl<T, E> Rule<T, E> for All<T, E> {
+    fn run(&self, t: &T) -> Result<(), E> {
+        for rule in &self.rules {
+            rule.run(t)?;
+        }
+        Ok(())
+    }
+}
+
+struct Any<T, E> {
+    rules: Vec<Box<dyn Rule<T, E>>>,
+}
+
+impl<T, E> Rule<T, E> for Any<T, E> {
+    fn run(&self, t: &T) -> Result<(), E> {
+        let mut last_err = None;
+        for rule in &self.rules {
+            match rule.run(t) {
+                Ok(()) => return Ok(()),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        match last_err {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+pub trait AsyncRule<T, E> {
+    async fn run(&self, t: &T) -> Result<(), E>;
+}
+
+pub struct AsyncValidator<T, E> {
+    rules: Vec<Box<dyn AsyncRule<T, E> + Send + Sync>>,
+}
+
+impl<T, E> AsyncValidator<T, E> {
+    pub fn new(rules: Vec<Box<dyn AsyncRule<T, E> + Send + Sync>>) -> Self {
+        Self { rules }
+    }
+
+    pub async fn validate(&self, t: &T) -> Vec<E>
+    where
+        T: Sync,
+    {
+        let results = futures::future::join_all(self.rules.iter().map(|rule| rule.run(t))).await;
+        let (_, errors): (Vec<_>, Vec<_>) = results.into_iter().partition(Result::is_ok);
+        errors.into_iter().map(Result::unwrap_err).collect()
+    }
+}
+
+struct SyncRuleAdapter<T, E> {
+    rule: Box<dyn Rule<T, E> + Send + Sync>,
+}
+
+#[async_trait::async_trait]
+impl<T, E> AsyncRule<T, E> for SyncRuleAdapter<T, E>
+where
+    T: Sync,
+    E: Send,
+{
+    async fn run(&self, t: &T) -> Result<(), E> {
+        self.rule.run(t)
+    }
+}
+
+pub fn from_sync<T, E>(
+    rule: Box<dyn Rule<T, E> + Send + Sync>,
+) -> Box<dyn AsyncRule<T, E> + Send + Sync>
+where
+    T: Sync + 'static,
+    E: Send + 'static,
+{
+    Box::new(SyncRuleAdapter { rule })
+}
+
+pub trait ContextRule<T, C, E> {
+    fn run(&self, t: &T, ctx: &C) -> Result<(), E>;
+}
+
+pub struct ContextValidator<T, C, E> {
+    rules: Vec<Box<dyn ContextRule<T, C, E>>>,
+}
+
+impl<T, C, E> ContextValidator<T, C, E> {
+    pub fn new(rules: Vec<Box<dyn ContextRule<T, C, E>>>) -> Self {
+        Self { rules }
+    }
+
+    pub fn validate(&self, t: &T, ctx: &C) -> Vec<E> {
+        let (_, errors): (Vec<_>, Vec<_>) = self
+            .rules
+            .iter()
+            .map(|rule| rule.run(t, ctx))
+            .partition(Result::is_ok);
+        errors.into_iter().map(Result::unwrap_err).collect()
+    }
+}
+
+struct FrozenContextRule<T, C, E> {
+    rule: Box<dyn ContextRule<T, C, E>>,
+    ctx: C,
+}
+
+impl<T, C, E> Rule<T, E> for FrozenContextRule<T, C, E> {
+    fn run(&self, t: &T) -> Result<(), E> {
+        self.rule.run(t, &self.ctx)
+    }
+}
+
+pub fn freeze<T, C, E>(rule: Box<dyn ContextRule<T, C, E>>, ctx: C) -> Box<dyn Rule<T, E>>
+where
+    T: 'static,
+    C: 'static,
+    E: 'static,
+{
+    Box::new(FrozenContextRule { rule, ctx })
+}
+
 pub struct Driver {
     pub age: u8,
     pub alcohol_in_blood: f32,
@@ -117,14 +412,16 @@ impl Rule<Driver, DriverError> for HasDrivingLicence {
     }
 }
 
-pub struct HasValidDrivingLicence {
-    date: DateTime<Utc>,
+pub struct HasValidDrivingLicence;
+
+pub struct ValidationContext {
+    pub now: DateTime<Utc>,
 }
 
-impl Rule<Driver, DriverError> for HasValidDrivingLicence {
-    fn run(&self, driver: &Driver) -> Result<(), DriverError> {
+impl ContextRule<Driver, ValidationContext, DriverError> for HasValidDrivingLicence {
+    fn run(&self, driver: &Driver, ctx: &ValidationContext) -> Result<(), DriverError> {
         driver.licence.map_or(Ok(()), |licence| {
-            if !licence.is_valid_in_date(self.date) {
+            if !licence.is_valid_in_date(ctx.now) {
                 return Err(LicenceExpired(licence.expiration));
             }
             Ok(())
@@ -132,7 +429,7 @@ impl Rule<Driver, DriverError> for HasValidDrivingLicence {
     }
 }
 
-#[derive(Debug, Error, PartialEq)]
+#[derive(Debug, Error, Clone, PartialEq)]
 pub enum DriverError {
     #[error("Alcohol level is: {} grams/lt ", .0)]
     AboveAllowedAlcoholLevel(f32),
@@ -144,6 +441,96 @@ pub enum DriverError {
     LicenceExpired(DateTime<Utc>),
 }
 
+#[derive(Debug, serde::Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum RuleSpec {
+    HasAge { required_age: u8 },
+    IsSober { allowed_level: f32 },
+    HasDrivingLicence,
+    HasValidDrivingLicence { date: DateTime<Utc> },
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("invalid rule configuration: {0}")]
+    Parse(String),
+    #[error("invalid parameter for rule {rule}: {reason}")]
+    InvalidParameter { rule: String, reason: String },
+}
+
+// serde-xml-rs cannot deserialize `RuleSpec`'s internally tagged representation
+// (it needs a variant identifier, not a map), so XML documents use the default,
+// externally tagged shape and get mapped onto the same `RuleSpec` variants.
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RuleSpecXml {
+    HasAge { required_age: u8 },
+    IsSober { allowed_level: f32 },
+    HasDrivingLicence,
+    HasValidDrivingLicence { date: DateTime<Utc> },
+}
+
+impl From<RuleSpecXml> for RuleSpec {
+    fn from(spec: RuleSpecXml) -> Self {
+        match spec {
+            RuleSpecXml::HasAge { required_age } => RuleSpec::HasAge { required_age },
+            RuleSpecXml::IsSober { allowed_level } => RuleSpec::IsSober { allowed_level },
+            RuleSpecXml::HasDrivingLicence => RuleSpec::HasDrivingLicence,
+            RuleSpecXml::HasValidDrivingLicence { date } => {
+                RuleSpec::HasValidDrivingLicence { date }
+            }
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct RuleSpecsXml {
+    #[serde(rename = "$value")]
+    rules: Vec<RuleSpecXml>,
+}
+
+impl DriverValidator {
+    pub fn from_json(json: &str) -> Result<DriverValidator, ConfigError> {
+        let specs: Vec<RuleSpec> =
+            serde_json::from_str(json).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        Self::from_specs(specs)
+    }
+
+    pub fn from_xml(xml: &str) -> Result<DriverValidator, ConfigError> {
+        let specs: RuleSpecsXml =
+            serde_xml_rs::from_str(xml).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        Self::from_specs(specs.rules.into_iter().map(RuleSpec::from).collect())
+    }
+
+    pub fn from_specs(specs: Vec<RuleSpec>) -> Result<DriverValidator, ConfigError> {
+        let mut builder = DriverValidatorBuilder::new();
+        for spec in specs {
+            builder = builder.with_rule(rule_from_spec(spec)?);
+        }
+        Ok(builder.build())
+    }
+}
+
+fn rule_from_spec(spec: RuleSpec) -> Result<Box<dyn Rule<Driver, DriverError>>, ConfigError> {
+    match spec {
+        RuleSpec::HasAge { required_age } => Ok(Box::new(HasAge { required_age })),
+        RuleSpec::IsSober { allowed_level } => {
+            if !(0.0..=5.0).contains(&allowed_level) {
+                return Err(ConfigError::InvalidParameter {
+                    rule: "is_sober".to_string(),
+                    reason: format!("allowed_level {} is out of range", allowed_level),
+                });
+            }
+            Ok(Box::new(IsSober { allowed_level }))
+        }
+        RuleSpec::HasDrivingLicence => Ok(Box::new(HasDrivingLicence)),
+        RuleSpec::HasValidDrivingLicence { date } => Ok(freeze(
+            Box::new(HasValidDrivingLicence),
+            ValidationContext { now: date },
+        )),
+    }
+}
+
 fn main() {}
 
 #[cfg(test)]
@@ -232,11 +619,10 @@ mod tests {
             }),
         };
 
-        let rule = HasValidDrivingLicence {
-            date: today,
-        };
+        let rule = HasValidDrivingLicence;
+        let ctx = ValidationContext { now: today };
 
-        let result = rule.run(&driver);
+        let result = rule.run(&driver, &ctx);
 
         match result {
             Ok(_) => panic!("should not happen"),
@@ -264,4 +650,385 @@ mod tests {
 
         assert_eq!(result.len(), 2)
     }
+
+    #[test]
+    pub fn and_fails_with_the_first_failing_rule_error() {
+        let driver = Driver {
+            age: 17,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let rule = HasAge { required_age: 18 }.and(Box::new(HasDrivingLicence));
+
+        let result = rule.run(&driver);
+
+        match result {
+            Ok(_) => panic!("should not happen"),
+            Err(e) => assert_eq!(UnderRequiredAge(17), e),
+        }
+    }
+
+    #[test]
+    pub fn and_succeeds_when_both_rules_succeed() {
+        let driver = Driver {
+            age: 18,
+            alcohol_in_blood: 0.0,
+            licence: Some(Licence {
+                licence_type: A,
+                expiration: Utc::now(),
+            }),
+        };
+
+        let rule = HasAge { required_age: 18 }.and(Box::new(HasDrivingLicence));
+
+        assert!(rule.run(&driver).is_ok());
+    }
+
+    #[test]
+    pub fn or_succeeds_when_either_rule_succeeds() {
+        let driver = Driver {
+            age: 18,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let rule = HasDrivingLicence.or(Box::new(HasAge { required_age: 18 }));
+
+        assert!(rule.run(&driver).is_ok());
+    }
+
+    #[test]
+    pub fn or_fails_with_the_last_failing_rule_error() {
+        let driver = Driver {
+            age: 17,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let rule = HasDrivingLicence.or(Box::new(HasAge { required_age: 18 }));
+
+        let result = rule.run(&driver);
+
+        match result {
+            Ok(_) => panic!("should not happen"),
+            Err(e) => assert_eq!(UnderRequiredAge(17), e),
+        }
+    }
+
+    #[test]
+    pub fn not_inverts_a_failing_rule_into_success() {
+        let driver = Driver {
+            age: 17,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let rule = HasAge { required_age: 18 }.not(WithoutLicence);
+
+        assert!(rule.run(&driver).is_ok());
+    }
+
+    #[test]
+    pub fn not_fails_with_the_supplied_error_when_the_inner_rule_passes() {
+        let driver = Driver {
+            age: 18,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let rule = HasAge { required_age: 18 }.not(WithoutLicence);
+
+        let result = rule.run(&driver);
+
+        match result {
+            Ok(_) => panic!("should not happen"),
+            Err(e) => assert_eq!(WithoutLicence, e),
+        }
+    }
+
+    #[test]
+    pub fn all_requires_every_rule_to_succeed() {
+        let driver = Driver {
+            age: 17,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let rule = all(vec![
+            Box::new(HasAge { required_age: 18 }),
+            Box::new(HasDrivingLicence),
+        ]);
+
+        let result = rule.run(&driver);
+
+        match result {
+            Ok(_) => panic!("should not happen"),
+            Err(e) => assert_eq!(UnderRequiredAge(17), e),
+        }
+    }
+
+    #[test]
+    pub fn any_succeeds_when_one_rule_succeeds() {
+        let driver = Driver {
+            age: 17,
+            alcohol_in_blood: 0.0,
+            licence: Some(Licence {
+                licence_type: A,
+                expiration: Utc::now(),
+            }),
+        };
+
+        let rule = any(vec![
+            Box::new(HasAge { required_age: 18 }),
+            Box::new(HasDrivingLicence),
+        ]);
+
+        assert!(rule.run(&driver).is_ok());
+    }
+
+    #[test]
+    pub fn from_specs_builds_a_validator_from_rule_specs() {
+        let driver = Driver {
+            age: 17,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let validator = DriverValidator::from_specs(vec![
+            RuleSpec::HasAge { required_age: 18 },
+            RuleSpec::HasDrivingLicence,
+        ])
+        .expect("specs should be valid");
+
+        let result = validator.validate(&driver);
+
+        assert_eq!(result.len(), 2)
+    }
+
+    #[test]
+    pub fn from_specs_rejects_an_out_of_range_allowed_level() {
+        let result = DriverValidator::from_specs(vec![RuleSpec::IsSober {
+            allowed_level: -1.0,
+        }]);
+
+        match result {
+            Ok(_) => panic!("should not happen"),
+            Err(ConfigError::InvalidParameter { rule, .. }) => assert_eq!("is_sober", rule),
+            Err(e) => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    pub fn from_json_rejects_an_unknown_rule_name() {
+        let result = DriverValidator::from_json(r#"[{"rule": "not_a_real_rule"}]"#);
+
+        assert!(matches!(result, Err(ConfigError::Parse(_))));
+    }
+
+    #[test]
+    pub fn from_json_builds_a_validator_from_a_config_document() {
+        let driver = Driver {
+            age: 18,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let validator = DriverValidator::from_json(r#"[{"rule": "has_age", "required_age": 18}]"#)
+            .expect("document should be valid");
+
+        assert!(validator.validate(&driver).is_empty());
+    }
+
+    #[test]
+    pub fn from_xml_rejects_an_unknown_rule_name() {
+        let result = DriverValidator::from_xml(r#"<rules><not_a_real_rule/></rules>"#);
+
+        assert!(matches!(result, Err(ConfigError::Parse(_))));
+    }
+
+    #[test]
+    pub fn from_xml_builds_a_validator_from_a_config_document() {
+        let driver = Driver {
+            age: 18,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let validator = DriverValidator::from_xml(
+            r#"<rules><has_age><required_age>18</required_age></has_age></rules>"#,
+        )
+        .expect("document should be valid");
+
+        assert!(validator.validate(&driver).is_empty());
+    }
+
+    #[tokio::test]
+    pub async fn async_validator_collects_errors_from_lifted_sync_rules() {
+        let driver = Driver {
+            age: 17,
+            alcohol_in_blood: 0.3,
+            licence: None,
+        };
+
+        let validator = AsyncValidator::new(vec![
+            from_sync(Box::new(HasDrivingLicence)),
+            from_sync(Box::new(HasAge { required_age: 18 })),
+        ]);
+
+        let result = validator.validate(&driver).await;
+
+        assert_eq!(result.len(), 2)
+    }
+
+    #[tokio::test]
+    pub async fn async_validator_succeeds_when_every_rule_passes() {
+        let driver = Driver {
+            age: 18,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let validator = AsyncValidator::new(vec![from_sync(Box::new(HasAge { required_age: 18 }))]);
+
+        assert!(validator.validate(&driver).await.is_empty());
+    }
+
+    #[test]
+    pub fn context_rule_reads_the_date_from_the_context() {
+        let today = Utc::now();
+        let expiration_date = today - Duration::days(1);
+        let driver = Driver {
+            age: 18,
+            alcohol_in_blood: 0.0,
+            licence: Some(Licence {
+                licence_type: A,
+                expiration: expiration_date,
+            }),
+        };
+
+        let rule = HasValidDrivingLicence;
+        let ctx = ValidationContext { now: today };
+
+        let result = rule.run(&driver, &ctx);
+
+        match result {
+            Ok(_) => panic!("should not happen"),
+            Err(e) => assert_eq!(LicenceExpired(expiration_date), e),
+        }
+    }
+
+    #[test]
+    pub fn context_validator_threads_the_same_context_to_every_rule() {
+        let today = Utc::now();
+        let driver = Driver {
+            age: 18,
+            alcohol_in_blood: 0.0,
+            licence: Some(Licence {
+                licence_type: A,
+                expiration: today + Duration::days(1),
+            }),
+        };
+
+        let validator: ContextValidator<Driver, ValidationContext, DriverError> =
+            ContextValidator::new(vec![Box::new(HasValidDrivingLicence)]);
+        let ctx = ValidationContext { now: today };
+
+        assert!(validator.validate(&driver, &ctx).is_empty());
+    }
+
+    #[test]
+    pub fn warnings_are_reported_separately_from_errors() {
+        let driver = Driver {
+            age: 17,
+            alcohol_in_blood: 0.0,
+            licence: None,
+        };
+
+        let validator = DriverValidatorBuilder::new()
+            .with_rule_severity(Box::new(HasAge { required_age: 18 }), Severity::Warning)
+            .with_rule(Box::new(HasDrivingLicence))
+            .build();
+
+        let report = validator.validate_report(&driver);
+
+        assert_eq!(report.errors, vec![WithoutLicence]);
+        assert_eq!(report.warnings, vec![UnderRequiredAge(17)]);
+        assert!(!report.is_accepted());
+    }
+
+    #[test]
+    pub fn a_subject_with_only_warnings_is_accepted() {
+        let driver = Driver {
+            age: 17,
+            alcohol_in_blood: 0.0,
+            licence: Some(Licence {
+                licence_type: A,
+                expiration: Utc::now(),
+            }),
+        };
+
+        let validator = DriverValidatorBuilder::new()
+            .with_rule_severity(Box::new(HasAge { required_age: 18 }), Severity::Warning)
+            .build();
+
+        let report = validator.validate_report(&driver);
+
+        assert!(report.is_accepted());
+    }
+
+    #[test]
+    pub fn short_circuit_mode_stops_at_the_first_error() {
+        let driver = Driver {
+            age: 17,
+            alcohol_in_blood: 0.6,
+            licence: None,
+        };
+
+        let validator = DriverValidatorBuilder::new()
+            .with_mode(Mode::ShortCircuit)
+            .with_rule(Box::new(HasAge { required_age: 18 }))
+            .with_rule(Box::new(IsSober {
+                allowed_level: 0.49,
+            }))
+            .with_rule(Box::new(HasDrivingLicence))
+            .build();
+
+        let report = validator.validate_report(&driver);
+
+        assert_eq!(report.errors, vec![UnderRequiredAge(17)]);
+    }
+
+    #[test]
+    pub fn rule_set_validates_domain_types_other_than_driver() {
+        struct Order {
+            total: u32,
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct OrderBelowMinimum(u32);
+
+        struct HasMinimumTotal {
+            minimum: u32,
+        }
+
+        impl Rule<Order, OrderBelowMinimum> for HasMinimumTotal {
+            fn run(&self, order: &Order) -> Result<(), OrderBelowMinimum> {
+                if order.total < self.minimum {
+                    return Err(OrderBelowMinimum(order.total));
+                }
+                Ok(())
+            }
+        }
+
+        let order = Order { total: 5 };
+
+        let validator: RuleSet<Order, OrderBelowMinimum> = RuleSetBuilder::new()
+            .with_rule(Box::new(HasMinimumTotal { minimum: 10 }))
+            .build();
+
+        let result = validator.validate(&order);
+
+        assert_eq!(result, vec![OrderBelowMinimum(5)]);
+    }
 }